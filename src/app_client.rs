@@ -37,7 +37,7 @@ use std::{
 	ops::Range,
 	sync::{Arc, Mutex},
 };
-use tokio::sync::broadcast;
+use tokio::{sync::broadcast, task::spawn_blocking};
 use tracing::{debug, error, info, instrument};
 
 use crate::{
@@ -83,6 +83,7 @@ struct AppClient {
 
 #[async_trait]
 impl Client for AppClient {
+	#[instrument(skip_all, fields(block = block_number), level = "trace")]
 	async fn reconstruct_rows_from_dht(
 		&self,
 		pp: Arc<PublicParameters>,
@@ -132,7 +133,9 @@ impl Client for AppClient {
 		)
 		.await?;
 
-		let reconstructed = reconstruct_columns(dimensions, &missing_fetched)?;
+		// Reconstruction is CPU heavy, so it is moved off the async executor threads
+		let reconstructed =
+			spawn_blocking(move || reconstruct_columns(dimensions, &missing_fetched)).await??;
 
 		debug!(
 			block_number,
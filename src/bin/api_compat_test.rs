@@ -1,12 +1,15 @@
 use avail_light::{
 	data::rocks_db::RocksDB,
 	network::rpc,
-	types::{ExponentialConfig, RetryConfig, State},
+	types::{ExponentialConfig, RetryConfig, RuntimeConfig, State},
 };
 use clap::Parser;
 use color_eyre::{eyre::Context, Result};
 use kate_recovery::matrix::Position;
-use std::sync::{Arc, Mutex};
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
 
 #[derive(Parser)]
 struct CommandArgs {
@@ -31,8 +34,15 @@ async fn main() -> Result<()> {
 		retries: 4,
 	});
 
-	let (rpc_client, _, subscriptions) =
-		rpc::init(db, state, &[command_args.url], "DEV", retry_cfg).await?;
+	let (rpc_client, _, subscriptions) = rpc::init(
+		db,
+		state,
+		&[command_args.url],
+		"DEV",
+		retry_cfg,
+		Duration::from_secs(RuntimeConfig::default().rpc_timeout),
+	)
+	.await?;
 	tokio::spawn(subscriptions.run());
 
 	let mut correct: bool = true;
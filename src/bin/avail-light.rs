@@ -25,6 +25,7 @@ use std::{
 	net::Ipv4Addr,
 	path::Path,
 	sync::{Arc, Mutex},
+	time::Duration,
 };
 use tokio::sync::{broadcast, mpsc};
 use tracing::{error, info, metadata::ParseLevelError, trace, warn, Level, Subscriber};
@@ -223,6 +224,7 @@ async fn run(shutdown: Controller<String>) -> Result<()> {
 		&cfg.full_node_ws,
 		&cfg.genesis_hash,
 		cfg.retry_config.clone(),
+		Duration::from_secs(cfg.rpc_timeout),
 	)
 	.await?;
 
@@ -0,0 +1,125 @@
+use avail_light::{
+	network::rpc,
+	proof,
+	types::{BlockVerified, ExponentialConfig, RetryConfig, RuntimeConfig, State},
+	utils::calculate_confidence,
+};
+use clap::Parser;
+use color_eyre::{
+	eyre::{eyre, Context},
+	Result,
+};
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+#[derive(Parser)]
+struct CommandArgs {
+	#[arg(short, long, value_name = "URL", default_value_t = String::from("ws://localhost:9944"))]
+	rpc: String,
+	/// Number of the block to sample
+	#[arg(short, long, value_name = "NUMBER")]
+	block: u32,
+	/// Confidence target in percent (50 - 99.99), used to calculate how many cells need to be sampled
+	#[arg(short, long, value_name = "PERCENT", default_value_t = 99.9, value_parser = parse_confidence)]
+	confidence: f64,
+}
+
+fn parse_confidence(value: &str) -> Result<f64, String> {
+	let confidence: f64 = value
+		.parse()
+		.map_err(|error| format!("Invalid confidence: {error}"))?;
+	if !(50.0..=99.99).contains(&confidence) {
+		return Err(format!(
+			"Confidence is in percent and must be between 50 and 99.99, got {confidence}"
+		));
+	}
+	Ok(confidence)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+	let command_args = CommandArgs::parse();
+	println!("Using URL: {}", command_args.rpc);
+
+	let state = Arc::new(Mutex::new(State::default()));
+	let retry_cfg = RetryConfig::Exponential(ExponentialConfig {
+		base: 10,
+		max_delay: 4000,
+		retries: 4,
+	});
+
+	let rpc_client = rpc::Client::new(
+		state,
+		rpc::Nodes::new(&[command_args.rpc]),
+		"DEV",
+		retry_cfg,
+		Duration::from_secs(RuntimeConfig::default().rpc_timeout),
+	)
+	.await?;
+
+	let block_number = command_args.block;
+	let (header, header_hash) = rpc_client
+		.get_header_by_block_number(block_number)
+		.await
+		.wrap_err(format!("Failed to get header for block {block_number}"))?;
+
+	let block = BlockVerified::try_from((header, None))?;
+	let Some(extension) = block.extension else {
+		println!("Block {block_number} has no data, nothing to sample");
+		return Ok(());
+	};
+
+	let dimensions = extension.dimensions;
+	if dimensions.cols().get() <= 2 {
+		return Err(eyre!(
+			"Block {block_number} cannot be sampled, more than 2 columns is required"
+		));
+	}
+
+	let cell_count = rpc::cell_count_for_confidence(command_args.confidence);
+	let positions = rpc::generate_random_cells(dimensions, cell_count);
+	println!(
+		"Sampling {} cells of block {block_number} ({dimensions:?})",
+		positions.len()
+	);
+
+	let cells = rpc_client
+		.request_kate_proof(header_hash, &positions)
+		.await
+		.wrap_err("Failed to fetch cells from node RPC")?;
+
+	let pp = Arc::new(kate_recovery::couscous::public_params());
+	let (verified, unverified) =
+		proof::verify(block_number, dimensions, &cells, &extension.commitments, pp)
+			.await
+			.wrap_err("Failed to verify fetched cells")?;
+
+	let confidence = calculate_confidence(verified.len() as u32);
+	println!(
+		"Verified {}/{} cells, confidence: {confidence}",
+		verified.len(),
+		positions.len()
+	);
+
+	if !unverified.is_empty() {
+		return Err(eyre!(
+			"Verification failed for {} cells: {unverified:?}",
+			unverified.len()
+		));
+	}
+
+	// Small matrices have fewer cells than required for the target confidence,
+	// in which case verifying all of them proves availability
+	let is_fully_verified = verified.len() == dimensions.extended_size() as usize;
+	if !is_fully_verified && confidence < command_args.confidence {
+		return Err(eyre!(
+			"Achieved confidence {confidence} is below the target {}",
+			command_args.confidence
+		));
+	}
+
+	println!("Block {block_number} is available");
+	Ok(())
+}
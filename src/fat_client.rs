@@ -13,7 +13,7 @@ use async_trait::async_trait;
 use avail_subxt::{primitives::Header, utils::H256};
 use codec::Encode;
 use color_eyre::{eyre::WrapErr, Result};
-use futures::future::join_all;
+use futures::{stream, StreamExt};
 use kate_recovery::{
 	data,
 	matrix::{Dimensions, Partition, Position},
@@ -22,7 +22,7 @@ use kate_recovery::{data::Cell, matrix::RowIndex};
 use mockall::automock;
 use sp_core::blake2_256;
 use std::{sync::Arc, time::Instant};
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, instrument, warn};
 
 use crate::{
 	data::{Database, Key},
@@ -72,6 +72,7 @@ impl Client for FatClient {
 	}
 }
 
+#[instrument(skip_all, fields(block = header.number), level = "trace")]
 pub async fn process_block(
 	client: &impl Client,
 	db: impl Database,
@@ -133,27 +134,36 @@ pub async fn process_block(
 	let begin = Instant::now();
 	let mut rpc_fetched: Vec<Cell> = vec![];
 
-	let get_kate_proof = |&n| client.get_kate_proof(header_hash, n);
-
-	let rpc_batches = positions.chunks(cfg.max_cells_per_rpc).collect::<Vec<_>>();
-	let parallel_batches = rpc_batches
-		.chunks(cfg.query_proof_rpc_parallel_tasks)
-		.map(|batch| join_all(batch.iter().map(get_kate_proof)));
-
-	for batch in parallel_batches {
-		for (i, result) in batch.await.into_iter().enumerate() {
-			let batch_rpc_fetched =
-				result.wrap_err(format!("Failed to fetch cells from node RPC at batch {i}"))?;
-
-			if let Err(e) = client
-				.insert_cells_into_dht(block_number, batch_rpc_fetched.clone())
-				.await
-			{
-				debug!("Error inserting cells into DHT: {e}");
-			}
-
-			rpc_fetched.extend(batch_rpc_fetched);
+	// Keep up to `query_proof_rpc_parallel_tasks` requests in flight,
+	// while still receiving batches in the order they were requested.
+	// At least one request has to be in flight, since `buffered(0)` never makes progress.
+	let parallel_tasks = cfg.query_proof_rpc_parallel_tasks.max(1);
+	let mut rpc_batches = stream::iter(positions.chunks(cfg.max_cells_per_rpc))
+		.map(|positions| async move {
+			let result = client.get_kate_proof(header_hash, positions).await;
+			(positions, result)
+		})
+		.buffered(parallel_tasks)
+		.enumerate();
+
+	while let Some((i, (positions, result))) = rpc_batches.next().await {
+		let (first, last) = (positions.first(), positions.last());
+		let batch_rpc_fetched = result.wrap_err(format!(
+			"Failed to fetch cells {first:?}..{last:?} of block {block_number} from node RPC at batch {i}"
+		))?;
+
+		if let Err(e) = client
+			.insert_cells_into_dht(block_number, batch_rpc_fetched.clone())
+			.await
+		{
+			debug!(
+				block_number,
+				batch = i,
+				"Error inserting cells {first:?}..{last:?} into DHT: {e:#}"
+			);
 		}
+
+		rpc_fetched.extend(batch_rpc_fetched);
 	}
 
 	let partition_rpc_retrieve_time_elapsed = begin.elapsed();
@@ -179,7 +189,7 @@ pub async fn process_block(
 		let data_rows = data::rows(dimensions, &data_cells);
 
 		if let Err(e) = client.insert_rows_into_dht(block_number, data_rows).await {
-			debug!("Error inserting rows into DHT: {e}");
+			debug!(block_number, "Error inserting rows into DHT: {e:#}");
 		}
 	} else {
 		warn!("No rows has been inserted into DHT since partition size is less than one row.")
@@ -26,7 +26,7 @@ use std::{
 	sync::{Arc, Mutex},
 	time::Instant,
 };
-use tracing::{error, info};
+use tracing::{error, info, instrument};
 
 use crate::{
 	data::{Database, Key},
@@ -40,6 +40,7 @@ use crate::{
 	utils::{calculate_confidence, extract_kate},
 };
 
+#[instrument(skip_all, fields(block = header.number), level = "trace")]
 pub async fn process_block(
 	db: impl Database,
 	network_client: &impl network::Client,
@@ -186,7 +186,7 @@ impl Client for DHTWithRPCFallbackClient {
 			.insert_cells_into_dht(block_number, rpc_fetched.clone())
 			.await
 		{
-			debug!("Error inserting cells into DHT: {error}");
+			debug!(block_number, "Error inserting cells into DHT: {error:#}");
 		}
 
 		let stats = FetchStats::new(
@@ -46,6 +46,8 @@ pub struct EventLoopEntries<'a> {
 	pending_swarm_events: &'a mut HashMap<PeerId, oneshot::Sender<Result<()>>>,
 	/// <block_num, (total_cells, result_cell_counter, time_stat)>
 	active_blocks: &'a mut HashMap<u32, BlockStat>,
+	/// Blocks for which all PUT operations have a result, to be reported by the event loop
+	completed_blocks: &'a mut Vec<u32>,
 }
 
 impl<'a> EventLoopEntries<'a> {
@@ -54,12 +56,14 @@ impl<'a> EventLoopEntries<'a> {
 		pending_kad_queries: &'a mut HashMap<QueryId, QueryChannel>,
 		pending_swarm_events: &'a mut HashMap<PeerId, oneshot::Sender<Result<()>>>,
 		active_blocks: &'a mut HashMap<u32, BlockStat>,
+		completed_blocks: &'a mut Vec<u32>,
 	) -> Self {
 		Self {
 			swarm,
 			pending_kad_queries,
 			pending_swarm_events,
 			active_blocks,
+			completed_blocks,
 		}
 	}
 
@@ -20,7 +20,7 @@ use std::{
 	time::{Duration, Instant},
 };
 use tokio::sync::oneshot;
-use tracing::{debug, trace};
+use tracing::{debug, trace, warn};
 
 #[derive(Clone)]
 pub struct Client {
@@ -78,6 +78,29 @@ impl BlockStat {
 		self.total_count += cell_number;
 		self.remaining_counter += cell_number;
 	}
+
+	/// Records results of given number of PUT operations.
+	/// Returns `true` if these results completed the block, meaning that all monitored records have a result.
+	pub fn record_results(&mut self, count: usize, is_error: bool) -> bool {
+		if count > self.remaining_counter {
+			warn!(
+				"Received {count} PUT results, but only {} are remaining",
+				self.remaining_counter
+			);
+		}
+		let was_completed = self.remaining_counter == 0;
+		self.remaining_counter = self.remaining_counter.saturating_sub(count);
+		if is_error {
+			self.error_counter += count;
+		} else {
+			self.success_counter += count;
+		}
+		!was_completed && self.remaining_counter == 0
+	}
+
+	pub fn success_rate(&self) -> f64 {
+		self.success_counter as f64 / self.total_count as f64
+	}
 }
 
 struct PruneExpiredRecords {
@@ -224,13 +247,36 @@ impl Command for PutKadRecord {
 				time_stat: 0,
 			});
 
+		let mut failed_count = 0;
 		for record in self.records.clone() {
-			let query_id = entries
+			match entries
 				.behavior_mut()
 				.kademlia
 				.put_record(record, self.quorum)
-				.expect("Unable to perform Kademlia PUT operation.");
-			entries.insert_query(query_id, QueryChannel::PutRecord);
+			{
+				Ok(query_id) => entries.insert_query(query_id, QueryChannel::PutRecord),
+				Err(error) => {
+					trace!("Unable to perform Kademlia PUT operation: {error}");
+					failed_count += 1;
+				},
+			}
+		}
+
+		// Failed records will never receive a PUT result, so count them as errors right away
+		if failed_count > 0 {
+			warn!(
+				"Failed to store {failed_count}/{} records for block {}",
+				self.records.len(),
+				self.block_num
+			);
+			let is_completed = entries
+				.active_blocks
+				.get_mut(&self.block_num)
+				.map(|block| block.record_results(failed_count, true))
+				.unwrap_or_default();
+			if is_completed {
+				entries.completed_blocks.push(self.block_num);
+			}
 		}
 		Ok(())
 	}
@@ -714,7 +760,7 @@ impl Client {
 
 	async fn insert_into_dht(&self, records: Vec<(String, Record)>, block_num: u32) -> Result<()> {
 		if records.is_empty() {
-			return Err(eyre!("Cant send empty record list."));
+			return Err(eyre!("Cant send empty record list for block {block_num}."));
 		}
 		self.put_kad_record(
 			records.into_iter().map(|e| e.1).collect(),
@@ -767,3 +813,63 @@ impl Client {
 		self.insert_into_dht(records, block).await
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::BlockStat;
+
+	#[test]
+	fn block_stat_record_results() {
+		let mut block = BlockStat {
+			total_count: 4,
+			remaining_counter: 4,
+			success_counter: 0,
+			error_counter: 0,
+			time_stat: 0,
+		};
+
+		assert!(!block.record_results(1, false));
+		assert!(!block.record_results(2, true));
+		assert_eq!(block.remaining_counter, 1);
+		assert_eq!(block.success_counter, 1);
+		assert_eq!(block.error_counter, 2);
+
+		assert!(block.record_results(1, true));
+		assert_eq!(block.remaining_counter, 0);
+		assert_eq!(block.error_counter, 3);
+		assert_eq!(block.success_rate(), 0.25);
+	}
+
+	#[test]
+	fn block_stat_all_records_failed() {
+		let mut block = BlockStat {
+			total_count: 3,
+			remaining_counter: 3,
+			success_counter: 0,
+			error_counter: 0,
+			time_stat: 0,
+		};
+
+		assert!(block.record_results(3, true));
+		assert_eq!(block.error_counter, 3);
+		assert_eq!(block.success_rate(), 0.0);
+	}
+
+	#[test]
+	fn block_stat_unexpected_results() {
+		let mut block = BlockStat {
+			total_count: 2,
+			remaining_counter: 2,
+			success_counter: 0,
+			error_counter: 0,
+			time_stat: 0,
+		};
+
+		assert!(block.record_results(3, false));
+		assert_eq!(block.remaining_counter, 0);
+
+		// Block is already completed, so it must not be reported again
+		assert!(!block.record_results(1, false));
+		assert_eq!(block.remaining_counter, 0);
+	}
+}
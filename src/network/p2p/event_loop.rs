@@ -183,7 +183,7 @@ impl EventLoop {
 			tokio::select! {
 				event = self.swarm.next() => self.handle_event(event.expect("Swarm stream should be infinite"), metrics.clone()).await,
 				command = command_receiver.recv() => match command {
-					Some(c) => self.handle_command(c).await,
+					Some(c) => self.handle_command(c, metrics.clone()).await,
 					//
 					None => {
 						warn!("Command channel closed, exiting the network event loop");
@@ -545,15 +545,22 @@ impl EventLoop {
 		}
 	}
 
-	async fn handle_command(&mut self, mut command: SendableCommand) {
+	async fn handle_command(&mut self, mut command: SendableCommand, metrics: Arc<impl Metrics>) {
+		let mut completed_blocks = vec![];
 		if let Err(err) = command.run(EventLoopEntries::new(
 			&mut self.swarm,
 			&mut self.pending_kad_queries,
 			&mut self.pending_swarm_events,
 			&mut self.active_blocks,
+			&mut completed_blocks,
 		)) {
 			command.abort(eyre!(err));
 		}
+
+		// PUT operations can fail before being sent, which can complete the block
+		for block_num in completed_blocks {
+			self.report_block_stat(block_num, metrics.clone()).await;
+		}
 	}
 
 	fn handle_periodic_bootstraps(&mut self) {
@@ -630,12 +637,7 @@ impl EventLoop {
 		};
 		if let Some(block) = self.active_blocks.get_mut(&block_num) {
 			// Decrement record counter for this block
-			block.remaining_counter -= 1;
-			if is_error {
-				block.error_counter += 1;
-			} else {
-				block.success_counter += 1;
-			}
+			let is_completed = block.record_results(1, is_error);
 
 			block.time_stat = stats
 				.duration()
@@ -643,19 +645,8 @@ impl EventLoop {
 				.map(Duration::as_secs)
 				.unwrap_or_default();
 
-			if block.remaining_counter == 0 {
-				let success_rate = block.success_counter as f64 / block.total_count as f64;
-				info!(
-					"Cell upload success rate for block {block_num}: {}/{}. Duration: {}",
-					block.success_counter, block.total_count, block.time_stat
-				);
-				_ = metrics
-					.record(MetricValue::DHTPutSuccess(success_rate))
-					.await;
-
-				_ = metrics
-					.record(MetricValue::DHTPutDuration(block.time_stat as f64))
-					.await;
+			if is_completed {
+				self.report_block_stat(block_num, metrics).await;
 			}
 
 			if self.event_loop_config.is_fat_client {
@@ -667,6 +658,23 @@ impl EventLoop {
 			debug!("Can't find block in the active blocks list")
 		}
 	}
+
+	async fn report_block_stat(&self, block_num: u32, metrics: Arc<impl Metrics>) {
+		let Some(block) = self.active_blocks.get(&block_num) else {
+			return;
+		};
+		info!(
+			"Cell upload success rate for block {block_num}: {}/{}. Duration: {}",
+			block.success_counter, block.total_count, block.time_stat
+		);
+		_ = metrics
+			.record(MetricValue::DHTPutSuccess(block.success_rate()))
+			.await;
+
+		_ = metrics
+			.record(MetricValue::DHTPutDuration(block.time_stat as f64))
+			.await;
+	}
 }
 
 #[cfg(test)]
@@ -10,6 +10,7 @@ use std::{
 	collections::HashSet,
 	fmt::Display,
 	sync::{Arc, Mutex},
+	time::Duration,
 };
 use tokio::{
 	sync::broadcast,
@@ -201,9 +202,16 @@ pub async fn init<T: Database>(
 	nodes: &[String],
 	genesis_hash: &str,
 	retry_config: RetryConfig,
+	rpc_timeout: Duration,
 ) -> Result<(Client, broadcast::Sender<Event>, SubscriptionLoop<T>)> {
-	let rpc_client =
-		Client::new(state.clone(), Nodes::new(nodes), genesis_hash, retry_config).await?;
+	let rpc_client = Client::new(
+		state.clone(),
+		Nodes::new(nodes),
+		genesis_hash,
+		retry_config,
+		rpc_timeout,
+	)
+	.await?;
 	// create output channel for RPC Subscription Events
 	let (event_sender, _) = broadcast::channel(1000);
 	let subscriptions =
@@ -214,6 +222,17 @@ pub async fn init<T: Database>(
 
 /// Generates random cell positions for sampling
 pub fn generate_random_cells(dimensions: Dimensions, cell_count: u32) -> Vec<Position> {
+	generate_random_cells_with_rng(dimensions, cell_count, &mut thread_rng())
+}
+
+/// Generates random cell positions for sampling, using provided random number generator.
+/// Positions are distinct and returned in the order they were generated,
+/// so seeded generator always yields the same positions.
+pub fn generate_random_cells_with_rng(
+	dimensions: Dimensions,
+	cell_count: u32,
+	rng: &mut impl Rng,
+) -> Vec<Position> {
 	let max_cells = dimensions.extended_size();
 	let count = if max_cells < cell_count {
 		debug!("Max cells count {max_cells} is lesser than cell_count {cell_count}");
@@ -221,15 +240,18 @@ pub fn generate_random_cells(dimensions: Dimensions, cell_count: u32) -> Vec<Pos
 	} else {
 		cell_count
 	};
-	let mut rng = thread_rng();
 	let mut indices = HashSet::new();
-	while (indices.len() as u16) < count as u16 {
+	let mut positions = Vec::with_capacity(count as usize);
+	while positions.len() < count as usize {
 		let col = rng.gen_range(0..dimensions.cols().into());
 		let row = rng.gen_range(0..dimensions.extended_rows());
-		indices.insert(Position { row, col });
+		let position = Position { row, col };
+		if indices.insert(position) {
+			positions.push(position);
+		}
 	}
 
-	indices.into_iter().collect::<Vec<_>>()
+	positions
 }
 
 /* @note: fn to take the number of cells needs to get equal to or greater than
@@ -277,3 +299,51 @@ pub async fn wait_for_finalized_header(
 		Err(_) => Err(eyre!("Timeout on waiting for first finalized header")),
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rand::SeedableRng;
+	use rand_chacha::ChaChaRng;
+	use test_case::test_case;
+
+	#[test]
+	fn test_generate_random_cells_with_seed() {
+		let dimensions = Dimensions::new(16, 32).unwrap();
+		let generate = || {
+			let mut rng = ChaChaRng::seed_from_u64(42);
+			generate_random_cells_with_rng(dimensions, 10, &mut rng)
+		};
+		let positions = generate();
+		assert_eq!(positions.len(), 10);
+		assert_eq!(positions, generate());
+
+		let unique = positions.iter().collect::<HashSet<_>>();
+		assert_eq!(unique.len(), positions.len());
+		assert!(positions
+			.iter()
+			.all(|p| p.row < dimensions.extended_rows() && p.col < dimensions.cols().get()));
+	}
+
+	#[test]
+	fn test_generate_random_cells_more_than_available() {
+		let dimensions = Dimensions::new(1, 4).unwrap();
+		let mut rng = ChaChaRng::seed_from_u64(42);
+		let positions = generate_random_cells_with_rng(dimensions, 20, &mut rng);
+		assert_eq!(positions.len(), dimensions.extended_size() as usize);
+	}
+
+	#[test_case(65_536 ; "count equal to u16 max plus one")]
+	#[test_case(70_000 ; "count above u16 max")]
+	#[test_case(200_000 ; "count above extended size")]
+	fn test_generate_random_cells_large_count(cell_count: u32) {
+		let dimensions = Dimensions::new(256, 256).unwrap();
+		let mut rng = ChaChaRng::seed_from_u64(42);
+		let positions = generate_random_cells_with_rng(dimensions, cell_count, &mut rng);
+		let expected = cell_count.min(dimensions.extended_size());
+		assert_eq!(positions.len(), expected as usize);
+
+		let unique = positions.iter().collect::<HashSet<_>>();
+		assert_eq!(unique.len(), positions.len());
+	}
+}
@@ -16,7 +16,10 @@ use color_eyre::{
 use futures::{Stream, TryFutureExt, TryStreamExt};
 use kate_recovery::{data::Cell, matrix::Position};
 use sp_core::{bytes::from_hex, ed25519::Public, U256};
-use std::sync::{Arc, Mutex};
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
 use subxt::{
 	backend::{
 		legacy::rpc_methods::{BlockNumber, StorageKey},
@@ -27,7 +30,7 @@ use subxt::{
 	utils::AccountId32,
 };
 use subxt_signer::sr25519::Keypair;
-use tokio::sync::RwLock;
+use tokio::{sync::RwLock, time::timeout};
 use tokio_retry::Retry;
 use tokio_stream::StreamExt;
 use tracing::{info, warn};
@@ -45,6 +48,8 @@ pub struct Client {
 	state: Arc<Mutex<State>>,
 	nodes: Nodes,
 	retry_config: RetryConfig,
+	/// Maximum duration of a single RPC call, before it is considered failed (zero disables the timeout)
+	rpc_timeout: Duration,
 	expected_genesis_hash: String,
 }
 
@@ -54,6 +59,7 @@ impl Client {
 		nodes: Nodes,
 		expected_genesis_hash: &str,
 		retry_config: RetryConfig,
+		rpc_timeout: Duration,
 	) -> Result<Self> {
 		// try and connect appropriate Node from the provided list
 		// will do retries with the provided Retry Config
@@ -76,6 +82,7 @@ impl Client {
 			state,
 			nodes,
 			retry_config,
+			rpc_timeout,
 			expected_genesis_hash: expected_genesis_hash.to_string(),
 		})
 	}
@@ -162,11 +169,37 @@ impl Client {
 		Err(eyre!("Failed to connect any appropriate working node"))
 	}
 
-	async fn with_retries<F, Fut, T>(&self, mut f: F) -> Result<T>
+	async fn with_retries<F, Fut, T>(&self, f: F) -> Result<T>
+	where
+		F: FnMut(Arc<AvailClient>) -> Fut + Copy,
+		Fut: std::future::Future<Output = Result<T, subxt::Error>>,
+	{
+		let rpc_timeout = (!self.rpc_timeout.is_zero()).then_some(self.rpc_timeout);
+		self.with_retries_and_timeout(rpc_timeout, f).await
+	}
+
+	async fn with_retries_and_timeout<F, Fut, T>(
+		&self,
+		rpc_timeout: Option<Duration>,
+		mut f: F,
+	) -> Result<T>
 	where
 		F: FnMut(Arc<AvailClient>) -> Fut + Copy,
 		Fut: std::future::Future<Output = Result<T, subxt::Error>>,
 	{
+		// fail the call if node doesn't respond in time, so it can be retried
+		let mut f = move |client: Arc<AvailClient>| {
+			let call = f(client);
+			async move {
+				let Some(duration) = rpc_timeout else {
+					return call.await;
+				};
+				timeout(duration, call).await.map_err(|_| {
+					subxt::Error::Other(format!("RPC call timed out after {duration:?}"))
+				})?
+			}
+		};
+
 		// try and execute the passed function, use the Retry strategy if needed
 		if let Ok(result) = Retry::spawn(self.retry_config.clone(), move || async move {
 			f(self.current_client().await).await
@@ -370,7 +403,10 @@ impl Client {
 		fn concat_content(scalar: U256, proof: GProof) -> Result<[u8; 80]> {
 			let proof: Vec<u8> = proof.into();
 			if proof.len() != 48 {
-				return Err(eyre!("Invalid proof length"));
+				return Err(eyre!(
+					"Invalid proof length: expected 48, got {}",
+					proof.len()
+				));
 			}
 
 			let mut result = [0u8; 80];
@@ -403,15 +439,23 @@ impl Client {
 			.await
 			.map_err(Report::from)?;
 
-		let contents = proofs
-			.into_iter()
-			.map(|(scalar, proof)| concat_content(scalar, proof).expect("TODO"));
+		if proofs.len() != positions.len() {
+			return Err(eyre!(
+				"Unexpected number of proofs: expected {}, got {}",
+				positions.len(),
+				proofs.len()
+			));
+		}
 
-		Ok(positions
+		positions
 			.iter()
-			.zip(contents)
-			.map(|(&position, content)| Cell { position, content })
-			.collect::<Vec<_>>())
+			.zip(proofs)
+			.map(|(&position, (scalar, proof))| {
+				concat_content(scalar, proof)
+					.wrap_err(format!("Invalid proof for cell {position:?}"))
+					.map(|content| Cell { position, content })
+			})
+			.collect::<Result<Vec<_>>>()
 	}
 
 	pub async fn get_system_version(&self) -> Result<String> {
@@ -475,7 +519,8 @@ impl Client {
 		app_id: AppId,
 	) -> Result<subxt::blocks::ExtrinsicEvents<AvailConfig>> {
 		let data = Arc::new(data);
-		self.with_retries(|client| {
+		// waiting for finalization takes multiple blocks, so RPC timeout is not applied
+		self.with_retries_and_timeout(None, |client| {
 			let data = data.clone();
 			async move {
 				tx::in_finalized(submit_data(&client, signer, data.as_ref(), app_id).await?)
@@ -491,7 +536,8 @@ impl Client {
 		&self,
 		tx_bytes: Vec<u8>,
 	) -> Result<subxt::blocks::ExtrinsicEvents<AvailConfig>> {
-		self.with_retries(|client| {
+		// waiting for finalization takes multiple blocks, so RPC timeout is not applied
+		self.with_retries_and_timeout(None, |client| {
 			let extrinsic =
 				SubmittableExtrinsic::from_bytes(client.online().clone(), tx_bytes.clone());
 			async move {
@@ -1,6 +1,6 @@
 //! Parallelized proof verification
 
-use color_eyre::eyre;
+use color_eyre::eyre::{self, eyre};
 use dusk_plonk::commitment_scheme::kzg10::PublicParameters;
 use itertools::{Either, Itertools};
 use kate_recovery::{
@@ -10,7 +10,7 @@ use kate_recovery::{
 };
 use std::sync::Arc;
 use tokio::{task::JoinSet, time::Instant};
-use tracing::debug;
+use tracing::{debug, instrument};
 
 async fn verify_proof(
 	public_parameters: Arc<PublicParameters>,
@@ -23,6 +23,7 @@ async fn verify_proof(
 }
 
 /// Verifies proofs for given block, cells and commitments
+#[instrument(skip_all, fields(block = block_num), level = "trace")]
 pub async fn verify(
 	block_num: u32,
 	dimensions: Dimensions,
@@ -39,10 +40,24 @@ pub async fn verify(
 	let mut tasks = JoinSet::new();
 
 	for cell in cells {
+		let Position { row, col } = cell.position;
+		if row >= dimensions.extended_rows() || col >= dimensions.cols().get() {
+			return Err(eyre!(
+				"Cell {:?} is out of bounds for block {block_num} matrix",
+				cell.position
+			));
+		}
+
+		let Some(commitment) = commitments.get(row as usize) else {
+			return Err(eyre!(
+				"Missing commitment for row {row} of block {block_num}"
+			));
+		};
+
 		tasks.spawn(verify_proof(
 			public_parameters.clone(),
 			dimensions,
-			commitments[cell.position.row as usize],
+			*commitment,
 			cell.clone(),
 		));
 	}
@@ -61,3 +76,113 @@ pub async fn verify(
 			false => Either::Right(position),
 		}))
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use kate_recovery::testnet;
+	use proptest::{
+		prelude::{any, Just},
+		prop_oneof, proptest,
+		strategy::Strategy,
+	};
+	use std::sync::OnceLock;
+	use test_case::test_case;
+
+	fn public_parameters() -> Arc<PublicParameters> {
+		static PUBLIC_PARAMETERS: OnceLock<Arc<PublicParameters>> = OnceLock::new();
+		PUBLIC_PARAMETERS
+			.get_or_init(|| Arc::new(testnet::public_params(1024)))
+			.clone()
+	}
+
+	fn verify_blocking(
+		dimensions: Dimensions,
+		position: Position,
+		commitments: &[[u8; 48]],
+	) -> eyre::Result<(Vec<Position>, Vec<Position>)> {
+		let cells = [Cell {
+			position,
+			content: [0u8; 80],
+		}];
+		tokio::runtime::Builder::new_current_thread()
+			.build()
+			.unwrap()
+			.block_on(verify(
+				1,
+				dimensions,
+				&cells,
+				commitments,
+				public_parameters(),
+			))
+	}
+
+	fn arb_dimensions() -> impl Strategy<Value = Dimensions> {
+		(1u16..=32, 1u16..=64).prop_filter_map("Invalid dimensions", |(rows, cols)| {
+			Dimensions::new(rows, cols)
+		})
+	}
+
+	fn arb_out_of_bounds_position(dimensions: Dimensions) -> impl Strategy<Value = Position> {
+		let rows = dimensions.extended_rows();
+		let cols = dimensions.cols().get();
+		prop_oneof![
+			(rows..=u32::MAX, any::<u16>()).prop_map(|(row, col)| Position { row, col }),
+			(0..rows, cols..=u16::MAX).prop_map(|(row, col)| Position { row, col }),
+		]
+	}
+
+	proptest! {
+	#[test]
+	fn verify_rejects_out_of_bounds_cell(
+		(dimensions, position) in arb_dimensions()
+			.prop_flat_map(|dimensions| (Just(dimensions), arb_out_of_bounds_position(dimensions)))
+	) {
+		let commitments = vec![[0u8; 48]; dimensions.extended_rows() as usize];
+		let error = verify_blocking(dimensions, position, &commitments).unwrap_err();
+		assert!(error.to_string().contains("out of bounds"), "{error}");
+	}
+	}
+
+	proptest! {
+	#[test]
+	fn verify_rejects_cell_without_commitment(
+		(dimensions, commitments_count, position) in arb_dimensions()
+			.prop_flat_map(|dimensions| {
+				let rows = dimensions.extended_rows();
+				(Just(dimensions), 0..rows)
+			})
+			.prop_flat_map(|(dimensions, commitments_count)| {
+				let rows = dimensions.extended_rows();
+				let cols = dimensions.cols().get();
+				let position = (commitments_count..rows, 0..cols)
+					.prop_map(|(row, col)| Position { row, col });
+				(Just(dimensions), Just(commitments_count), position)
+			})
+	) {
+		let commitments = vec![[0u8; 48]; commitments_count as usize];
+		let error = verify_blocking(dimensions, position, &commitments).unwrap_err();
+		assert!(error.to_string().contains("Missing commitment"), "{error}");
+	}
+	}
+
+	#[test_case(Position { row: 2, col: 0 } ; "row out of bounds")]
+	#[test_case(Position { row: 0, col: 4 } ; "col out of bounds")]
+	fn test_verify_out_of_bounds_cell(position: Position) {
+		let dimensions = Dimensions::new(1, 4).unwrap();
+		let commitments = [[0u8; 48]; 2];
+
+		let error = verify_blocking(dimensions, position, &commitments).unwrap_err();
+		assert!(error.to_string().contains("out of bounds"), "{error}");
+	}
+
+	#[test]
+	fn test_verify_missing_commitment() {
+		let dimensions = Dimensions::new(1, 4).unwrap();
+		let commitments = [[0u8; 48]; 1];
+		let position = Position { row: 1, col: 0 };
+
+		let error = verify_blocking(dimensions, position, &commitments).unwrap_err();
+		assert!(error.to_string().contains("Missing commitment"), "{error}");
+	}
+}
@@ -41,7 +41,7 @@ use std::{
 	time::Instant,
 };
 use tokio::sync::broadcast;
-use tracing::{error, info, warn};
+use tracing::{error, info, instrument, warn};
 
 #[async_trait]
 #[automock]
@@ -109,6 +109,7 @@ impl<T: Database + Sync> Client for SyncClient<T> {
 	}
 }
 
+#[instrument(skip_all, fields(block = header.number), level = "trace")]
 async fn process_block(
 	client: &impl Client,
 	network_client: &impl network::Client,
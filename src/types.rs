@@ -434,6 +434,8 @@ pub struct RuntimeConfig {
 	pub sync_finality_enable: bool,
 	/// Maximum number of cells per request for proof queries (default: 30).
 	pub max_cells_per_rpc: Option<usize>,
+	/// Timeout for a single RPC call to the node, in seconds. Timed out calls are retried. If set to 0, timeout is disabled (default: 10).
+	pub rpc_timeout: u64,
 	/// Threshold for the number of cells fetched via DHT for the app client (default: 5000)
 	pub threshold: usize,
 	/// Kademlia configuration - WARNING: Changing the default values might cause the peer to suffer poor performance!
@@ -845,6 +847,7 @@ impl Default for RuntimeConfig {
 			sync_start_block: None,
 			sync_finality_enable: false,
 			max_cells_per_rpc: Some(30),
+			rpc_timeout: 10,
 			kad_record_ttl: 24 * 60 * 60,
 			threshold: 5000,
 			replication_factor: 5,